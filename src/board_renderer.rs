@@ -1,8 +1,21 @@
-use crate::game::Game;
+use crate::game::{BoundaryMode, Game};
+use crate::rule::Rule;
 use macroquad::{prelude::*, ui::root_ui};
+use std::collections::HashSet;
 
 const DEFAULT_SPEED: f32 = 10.0;
 
+/// File patterns are loaded from and saved to, in RLE format.
+const PATTERN_FILE: &str = "pattern.rle";
+
+/// Named rulestring presets offered in the menu, so users can explore other automata without
+/// recompiling.
+const RULE_PRESETS: &[(&str, &str)] = &[
+    ("Conway", "B3/S23"),
+    ("HighLife", "B36/S23"),
+    ("Seeds", "B2/S"),
+];
+
 /// Responsible for rendering the game board and handling UI elements.
 pub struct BoardRenderer {
     pub ncols: usize,
@@ -49,10 +62,17 @@ impl BoardRenderer {
                 20.0,
                 GRAY,
             );
+            draw_text(
+                "Press N to step one frame while paused",
+                screen_width() / 2.0 - 170.0,
+                screen_height() / 2.0 + 110.0,
+                20.0,
+                GRAY,
+            );
             draw_text(
                 "Press Enter to start",
                 screen_width() / 2.0 - 100.0,
-                screen_height() / 2.0 + 140.0,
+                screen_height() / 2.0 + 170.0,
                 20.0,
                 WHITE,
             );
@@ -66,25 +86,56 @@ impl BoardRenderer {
 
     /// Displays initial instructions for the game.
     ///
-    /// This function renders initial instructions for selecting alive cells and
-    /// randomizing the board.
+    /// This function renders initial instructions for selecting alive cells, randomizing the
+    /// board, and loading/saving a pattern in RLE format.
     pub fn show_initial_instructions() {
         draw_text(
             "Select alive cells and press Enter to Start",
             screen_width() / 2.0 - 180.0,
-            screen_height() - 40.0,
+            screen_height() - 60.0,
             20.0,
             WHITE,
         );
         draw_text(
             "Press 'R' to randomize",
             screen_width() / 2.0 - 100.0,
+            screen_height() - 40.0,
+            20.0,
+            WHITE,
+        );
+        draw_text(
+            format!("Press 'L' to load / 'S' to save {PATTERN_FILE}"),
+            screen_width() / 2.0 - 160.0,
             screen_height() - 20.0,
             20.0,
             WHITE,
         );
     }
 
+    /// Loads a pattern from [`PATTERN_FILE`] into `game`, replacing its current cells.
+    ///
+    /// Read and parse failures are reported to stderr and otherwise ignored, leaving `game`
+    /// untouched.
+    fn load_pattern_file(game: &mut Game) {
+        match std::fs::read_to_string(PATTERN_FILE) {
+            Ok(rle) => {
+                if let Err(err) = game.load_rle(&rle, (0, 0)) {
+                    eprintln!("Failed to parse {PATTERN_FILE}: {err}");
+                }
+            }
+            Err(err) => eprintln!("Failed to read {PATTERN_FILE}: {err}"),
+        }
+    }
+
+    /// Saves the current board to [`PATTERN_FILE`] in RLE format.
+    ///
+    /// Write failures are reported to stderr and otherwise ignored.
+    fn save_pattern_file(game: &Game) {
+        if let Err(err) = std::fs::write(PATTERN_FILE, game.to_rle()) {
+            eprintln!("Failed to write {PATTERN_FILE}: {err}");
+        }
+    }
+
     /// Draws the grid lines for the Game of Life.
     ///
     /// This function renders the grid lines onto the screen to visually separate the cells.
@@ -106,38 +157,36 @@ impl BoardRenderer {
 
     /// Draws the current game state cells.
     ///
-    /// This function renders the cells of the game onto the screen using the cell size
-    /// to determine their position and dimensions. Only alive cells are drawn.
+    /// This function renders the live cells of the game onto the screen using the cell size
+    /// to determine their position and dimensions. Only cells within the visible viewport
+    /// (`[0, ncols) x [0, nrows)`) are drawn, since the game's live-cell set is otherwise unbounded.
     ///
     /// # Arguments
     ///
-    /// * `cells` - A reference to a 2D vector representing the game grid. Each element
-    ///   is a boolean indicating whether the cell is alive (`true`) or dead (`false`).
-    pub fn draw_cells(&self, cells: &[Vec<bool>]) {
-        #[allow(clippy::needless_range_loop)] // this way is clearer than how Clippy suggests
-        for x in 0..self.ncols {
-            for y in 0..self.nrows {
-                if cells[x][y] {
-                    draw_rectangle(
-                        (x * self.cell_size) as f32,
-                        (y * self.cell_size) as f32,
-                        self.cell_size as f32,
-                        self.cell_size as f32,
-                        WHITE,
-                    );
-                }
+    /// * `cells` - A reference to the set of live cell coordinates.
+    pub fn draw_cells(&self, cells: &HashSet<(i64, i64)>) {
+        for &(x, y) in cells {
+            if x >= 0 && y >= 0 && (x as usize) < self.ncols && (y as usize) < self.nrows {
+                draw_rectangle(
+                    (x as usize * self.cell_size) as f32,
+                    (y as usize * self.cell_size) as f32,
+                    self.cell_size as f32,
+                    self.cell_size as f32,
+                    WHITE,
+                );
             }
         }
     }
 
-    /// Draws the game menu with options for restarting and pausing/unpausing the game.
+    /// Draws the game menu with options for restarting, pausing/unpausing and cycling the rule.
     ///
     /// # Arguments
     ///
     /// * `paused` - A mutable reference to a boolean that indicates whether the game is currently paused.
     /// * `restart` - A mutable reference to a boolean that is set to `true` when the "Restart Game" button is pressed.
-    pub async fn draw_menu(paused: &mut bool, restart: &mut bool) {
-        let menu_height = 200.0;
+    /// * `game` - The game whose rule is updated when the user cycles presets.
+    pub async fn draw_menu(paused: &mut bool, restart: &mut bool, game: &mut Game) {
+        let menu_height = 460.0;
         let menu_width = 250.0;
         let menu_x = (screen_width() - menu_width) / 2.0;
         let menu_y = (screen_height() - menu_height) / 2.0;
@@ -155,34 +204,131 @@ impl BoardRenderer {
                 if ui.button(None, if *paused { "Unpause" } else { "Pause" }) {
                     *paused = !*paused;
                 }
+                // Resolved from `game.rule` itself, rather than tracked as separate state, so the
+                // label can't drift out of sync after something else (e.g. loading an RLE file
+                // with its own `rule = ...` header) changes the active rule.
+                let rule_index = RULE_PRESETS.iter().position(|&(_, rulestring)| {
+                    Rule::parse(rulestring).expect("presets are valid rulestrings") == game.rule
+                });
+                let rule_label = match rule_index {
+                    Some(i) => format!("Rule: {}", RULE_PRESETS[i].0),
+                    None => format!("Rule: {}", game.rule.to_rulestring()),
+                };
+                if ui.button(None, rule_label) {
+                    let next_preset = rule_index.map_or(0, |i| (i + 1) % RULE_PRESETS.len());
+                    game.rule = Rule::parse(RULE_PRESETS[next_preset].1).expect("presets are valid rulestrings");
+                }
+                let boundary_label = match game.boundary {
+                    BoundaryMode::Dead => "Boundary: Dead",
+                    BoundaryMode::Toroidal => "Boundary: Toroidal",
+                };
+                if ui.button(None, boundary_label) {
+                    let next_boundary = match game.boundary {
+                        BoundaryMode::Dead => BoundaryMode::Toroidal,
+                        BoundaryMode::Toroidal => BoundaryMode::Dead,
+                    };
+                    game.set_boundary(next_boundary);
+                }
+                ui.separator();
+                ui.label(None, &format!("Generation: {}", game.generation));
+                ui.label(None, &format!("Seed interval: {}", game.seed_interval));
+                if ui.button(None, "Interval +10") {
+                    game.seed_interval += 10;
+                }
+                if ui.button(None, "Interval -10") {
+                    game.seed_interval = game.seed_interval.saturating_sub(10);
+                }
+                ui.label(None, &format!("Seed density: {:.2}", game.seed_density));
+                if ui.button(None, "Density +0.05") {
+                    game.seed_density = (game.seed_density + 0.05).min(1.0);
+                }
+                if ui.button(None, "Density -0.05") {
+                    game.seed_density = (game.seed_density - 0.05).max(0.0);
+                }
                 ui.label(None, "Press 'M' to close the menu");
             },
         );
     }
 
+    /// Converts the current mouse position into grid coordinates.
+    fn mouse_grid_pos(&self) -> (i64, i64) {
+        let mouse_pos = mouse_position();
+        (
+            (mouse_pos.0 / self.cell_size as f32) as i64,
+            (mouse_pos.1 / self.cell_size as f32) as i64,
+        )
+    }
+
+    /// Sets every cell alive along the line from `(x0, y0)` to `(x1, y1)` using Bresenham's
+    /// line algorithm, so a fast drag doesn't leave gaps between sampled mouse positions.
+    fn draw_line(game: &mut Game, (x0, y0): (i64, i64), (x1, y1): (i64, i64)) {
+        let dx = (x1 - x0).abs();
+        let dy = -(y1 - y0).abs();
+        let sx = if x0 < x1 { 1 } else { -1 };
+        let sy = if y0 < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+        let (mut x, mut y) = (x0, y0);
+
+        loop {
+            if x >= 0 && y >= 0 {
+                game.set_cell_state(x as usize, y as usize, true);
+            }
+            if x == x1 && y == y1 {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+    }
+
     /// Allows the player to choose the initial alive cells through a GUI.
     ///
-    /// This function allows the player to click on cells to toggle their state
-    /// (alive or dead) before starting the game. The player can also randomize
-    /// the initial state by pressing 'R'.
+    /// This function allows the player to click on cells to toggle their state (alive or dead),
+    /// or hold the left mouse button and drag to paint a continuous line of live cells, before
+    /// starting the game. The player can also randomize the initial state by pressing 'R'.
     async fn choose_initial_state(&mut self, game: &mut Game) {
         let mut choosing = true;
-        
+        let mut drag_pos: Option<(i64, i64)> = None;
+
         while choosing {
             clear_background(BLACK);
             self.draw_grid();
             self.draw_cells(&game.cells);
-            
+
             if is_mouse_button_pressed(MouseButton::Left) {
-                let mouse_pos = mouse_position();
-                let x = (mouse_pos.0 / self.cell_size as f32) as usize;
-                let y = (mouse_pos.1 / self.cell_size as f32) as usize;
-                game.toggle_cell_state(x, y);
+                let pos = self.mouse_grid_pos();
+                if pos.0 >= 0 && pos.1 >= 0 {
+                    game.toggle_cell_state(pos.0 as usize, pos.1 as usize);
+                }
+                drag_pos = Some(pos);
+            } else if is_mouse_button_down(MouseButton::Left) {
+                let pos = self.mouse_grid_pos();
+                if let Some(prev) = drag_pos {
+                    if prev != pos {
+                        Self::draw_line(game, prev, pos);
+                    }
+                }
+                drag_pos = Some(pos);
+            } else {
+                drag_pos = None;
             }
             if is_key_pressed(KeyCode::R) {
                 game.randomize();
             }
-            
+            if is_key_pressed(KeyCode::L) {
+                Self::load_pattern_file(game);
+            }
+            if is_key_pressed(KeyCode::S) {
+                Self::save_pattern_file(game);
+            }
+
             Self::show_initial_instructions();
             next_frame().await;
             
@@ -192,14 +338,16 @@ impl BoardRenderer {
         }
     }
     
-    /// Checks for key presses to pause/unpause the game, adjust the speed and show the menu.
+    /// Checks for key presses to pause/unpause the game, adjust the speed, show the menu, and
+    /// (while paused) step a single generation.
     ///
     /// # Arguments
     ///
     /// * `paused` - A mutable reference to a boolean that indicates whether the game is paused.
     /// * `speed` - A mutable reference to a float representing the current game speed.
     /// * `show_menu` - A mutable reference to a boolean that controls the visibility of the menu.
-    fn check_keys(&mut self, paused: &mut bool, speed: &mut f32, show_menu: &mut bool) {
+    /// * `frame_step` - A mutable reference set to `true` when a single-generation step was requested.
+    fn check_keys(&mut self, paused: &mut bool, speed: &mut f32, show_menu: &mut bool, frame_step: &mut bool) {
         if is_key_pressed(KeyCode::Space) {
             *paused = !*paused;
         }
@@ -212,6 +360,9 @@ impl BoardRenderer {
         if is_key_pressed(KeyCode::M) {
             *show_menu = !*show_menu;
         }
+        if *paused && is_key_pressed(KeyCode::N) {
+            *frame_step = true;
+        }
     }
     
     /// Resets the game state and restarts the game.
@@ -219,7 +370,14 @@ impl BoardRenderer {
     /// This function clears the current state of the cells, effectively resetting the game board
     /// to its initial empty state. It then prompts the user to choose a new initial state for the cells.
     pub async fn restart(&mut self, game: &mut Game) {
-        *game = Game::new(self.ncols, self.nrows);
+        let rule = game.rule;
+        let boundary = game.boundary;
+        let seed_interval = game.seed_interval;
+        let seed_density = game.seed_density;
+        *game = Game::new(self.ncols, self.nrows, rule);
+        game.boundary = boundary;
+        game.seed_interval = seed_interval;
+        game.seed_density = seed_density;
         self.choose_initial_state(game).await;
     }
     
@@ -228,11 +386,14 @@ impl BoardRenderer {
     /// This function manages the game's lifecycle, including displaying the start menu,
     /// allowing the player to choose the initial state of the cells, and continuously updating
     /// and rendering the game state. The game can be paused or unpaused by pressing the Space
-    /// key, and the game speed can be adjusted using the Up and Down arrow keys.
+    /// key, and the game speed can be adjusted using the Up and Down arrow keys. While paused,
+    /// pressing 'N' advances the simulation by exactly one generation.
     ///
     /// The `run` function performs the following actions in its main loop:
-    /// - Checks for key presses to pause/unpause the game, adjust the game speed or show the menu.
-    /// - Updates the game state if the game is not paused.
+    /// - Checks for key presses to pause/unpause the game, adjust the game speed, show the menu,
+    ///   or step a single generation.
+    /// - Updates the game state if the game is not paused, or by exactly one generation if a
+    ///   frame step was requested while paused.
     /// - Draws the game grid and cells.
     /// - Displays the menu when requested and handles restarting the game if necessary.
     /// - Waits for the next frame to be drawn, allowing for smooth animation.
@@ -249,8 +410,9 @@ impl BoardRenderer {
         let mut restart = false;
         let mut speed = DEFAULT_SPEED;
         let mut update_timer = 0.0;
+        let mut frame_step = false;
         loop {
-            self.check_keys(&mut paused, &mut speed, &mut show_menu);
+            self.check_keys(&mut paused, &mut speed, &mut show_menu, &mut frame_step);
 
             if !paused {
                 update_timer += get_frame_time();
@@ -258,9 +420,12 @@ impl BoardRenderer {
                     game.update();
                     update_timer = 0.0;
                 }
+            } else if frame_step {
+                game.update();
             }
+            frame_step = false;
             if show_menu {
-                Self::draw_menu(&mut paused, &mut restart).await;
+                Self::draw_menu(&mut paused, &mut restart, game).await;
             }
             if restart {
                 self.restart(game).await;
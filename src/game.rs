@@ -1,6 +1,33 @@
+use crate::rule::Rule;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// Boundary behavior used when counting neighbors near the edge of the board.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BoundaryMode {
+    /// Neighbor coordinates are never wrapped: cells past the edge of the board are tracked
+    /// like any other, so patterns are free to travel past the board's initial dimensions.
+    Dead,
+    /// The board wraps around: the left edge is adjacent to the right edge, and the top to the
+    /// bottom, so patterns loop instead of escaping.
+    Toroidal,
+}
+
 /// Represents the state of the Game of Life.
+///
+/// Live cells are stored as a sparse set of coordinates rather than a dense grid, so the cost
+/// of a generation is proportional to the population rather than the board area, and patterns
+/// are free to travel beyond the board's initial dimensions.
 pub struct Game {
-    pub cells: Vec<Vec<bool>>,
+    pub cells: HashSet<(i64, i64)>,
+    pub rule: Rule,
+    pub boundary: BoundaryMode,
+    /// The number of generations that have elapsed since this `Game` was created.
+    pub generation: usize,
+    /// Reseed the board every `seed_interval` generations; `0` disables reseeding.
+    pub seed_interval: usize,
+    /// The fraction of the board's cells to randomly set alive on each reseed.
+    pub seed_density: f32,
     ncols: usize,
     nrows: usize,
 }
@@ -8,25 +35,59 @@ pub struct Game {
 impl Game {
     /// Creates a new `Game` instance with all cells initially dead.
     ///
-    /// This function initializes a new `Game` with the given dimensions and prepares a grid where all cells are dead.
+    /// This function initializes a new `Game` with the given dimensions and rule, a `Dead`
+    /// boundary, reseeding disabled, and an empty set of live cells.
     ///
     /// # Arguments
     ///
     /// * `ncols` - The number of columns in the game grid.
     /// * `nrows` - The number of rows in the game grid.
+    /// * `rule` - The birth/survival rule to advance the game with.
     ///
     /// # Returns
     ///
-    /// A new `Game` instance with the specified dimensions, and with all cells initially set to `false` (dead).
-    pub fn new(ncols: usize, nrows: usize) -> Self {
-        let cells = vec![vec![false; nrows]; ncols];
+    /// A new `Game` instance with the specified dimensions and rule, and with all cells initially dead.
+    pub fn new(ncols: usize, nrows: usize, rule: Rule) -> Self {
         Self {
-            cells,
+            cells: HashSet::new(),
+            rule,
+            boundary: BoundaryMode::Dead,
+            generation: 0,
+            seed_interval: 0,
+            seed_density: 0.02,
             ncols,
             nrows,
         }
     }
 
+    /// Changes the boundary mode used for neighbor counting.
+    ///
+    /// Switching to `Toroidal` wraps any live cell whose coordinate currently lies outside
+    /// `[0, ncols) x [0, nrows)` back into range. Such cells are left behind by `Dead` mode,
+    /// where patterns are free to drift past the board's original dimensions, or by
+    /// `load_rle` stamping a pattern that extends past the board; without this,
+    /// they'd vanish the instant wrapping started being applied to them, since `update()` only
+    /// ever counts neighbors at wrapped coordinates, never at the escaped cell's own.
+    ///
+    /// # Arguments
+    ///
+    /// * `boundary` - The boundary mode to switch to.
+    pub fn set_boundary(&mut self, boundary: BoundaryMode) {
+        if boundary == BoundaryMode::Toroidal {
+            self.cells = self
+                .cells
+                .iter()
+                .map(|&(x, y)| {
+                    (
+                        x.rem_euclid(self.ncols as i64),
+                        y.rem_euclid(self.nrows as i64),
+                    )
+                })
+                .collect();
+        }
+        self.boundary = boundary;
+    }
+
     /// This function changes the state of the cell at the given `(x, y)` coordinates from alive to dead or vice versa.
     ///
     /// # Arguments
@@ -35,121 +96,490 @@ impl Game {
     /// * `y` - The row index of the cell to be toggled. It must be within the range `[0, nrows)`.
     pub fn toggle_cell_state(&mut self, x: usize, y: usize) {
         if x < self.ncols && y < self.nrows {
-            self.cells[x][y] = !self.cells[x][y];
+            let coord = (x as i64, y as i64);
+            if !self.cells.remove(&coord) {
+                self.cells.insert(coord);
+            }
+        }
+    }
+
+    /// Sets the cell at the given `(x, y)` coordinates to alive or dead, unlike
+    /// `toggle_cell_state` which flips whatever state is already there.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The column index of the cell to set. It must be within the range `[0, ncols)`.
+    /// * `y` - The row index of the cell to set. It must be within the range `[0, nrows)`.
+    /// * `alive` - Whether the cell should be alive (`true`) or dead (`false`).
+    pub fn set_cell_state(&mut self, x: usize, y: usize, alive: bool) {
+        if x < self.ncols && y < self.nrows {
+            let coord = (x as i64, y as i64);
+            if alive {
+                self.cells.insert(coord);
+            } else {
+                self.cells.remove(&coord);
+            }
         }
     }
 
     /// Randomizes the state of all cells in the grid.
     ///
-    /// This function sets each cell in the grid to a random state (alive or dead).
+    /// This function sets each cell within the board's dimensions to a random state (alive or dead).
     pub fn randomize(&mut self) {
-        self.cells = (0..self.cells.len())
-            .map(|_| (0..self.cells[0].len()).map(|_| ::rand::random()).collect())
+        self.cells = (0..self.ncols)
+            .flat_map(|x| (0..self.nrows).map(move |y| (x as i64, y as i64)))
+            .filter(|_| ::rand::random())
             .collect();
     }
 
     /// Updates the game state to the next generation.
     ///
-    /// This function calculates the next state of the game based on the current state
-    /// and updates the cells accordingly.
+    /// This function tallies live-neighbor counts for every coordinate adjacent to a live cell,
+    /// then applies `self.rule` to those coordinates and the live cells themselves instead of
+    /// scanning the whole board: a live cell survives when its neighbor count is in
+    /// `rule.survive`, and a dead cell is born when its neighbor count is in `rule.birth`. Dead
+    /// cells with no live neighbors are never examined, so a `0` in `rule.birth` has no effect.
+    /// In `Toroidal` mode, neighbor coordinates wrap
+    /// around the board's dimensions instead of drifting off to infinity.
     pub fn update(&mut self) {
-        let mut next_cells = vec![vec![false; self.nrows]; self.ncols];
+        let mut neighbor_counts: HashMap<(i64, i64), u8> = HashMap::new();
+        for &(x, y) in &self.cells {
+            // Collect the distinct neighbor coordinates for this cell before counting: on a
+            // toroidal board no more than 2 cells wide/tall, several of the 8 raw offsets wrap
+            // onto the same coordinate, and counting each occurrence separately would inflate a
+            // single real neighbor into 2 or 3.
+            let mut neighbors: HashSet<(i64, i64)> = HashSet::with_capacity(8);
+            for dx in -1..=1 {
+                for dy in -1..=1 {
+                    if dx == 0 && dy == 0 {
+                        continue;
+                    }
+                    let neighbor = match self.boundary {
+                        BoundaryMode::Dead => (x + dx, y + dy),
+                        BoundaryMode::Toroidal => (
+                            (x + dx).rem_euclid(self.ncols as i64),
+                            (y + dy).rem_euclid(self.nrows as i64),
+                        ),
+                    };
+                    // In degenerate 1xN/Nx1 boards, a wrapped offset can land back on the cell
+                    // itself; that's never a real neighbor.
+                    if neighbor != (x, y) {
+                        neighbors.insert(neighbor);
+                    }
+                }
+            }
+            for neighbor in neighbors {
+                *neighbor_counts.entry(neighbor).or_insert(0) += 1;
+            }
+        }
+        // A live cell with no live neighbors is absent from the tally above, but
+        // `rule.survive[0]` still applies to it.
+        for &coord in &self.cells {
+            neighbor_counts.entry(coord).or_insert(0);
+        }
 
-        #[allow(clippy::needless_range_loop)] // this way is clearer than how Clippy suggests
-        for x in 0..self.ncols {
-            for y in 0..self.nrows {
-                let cell = self.cells[x][y];
-                let neighbors = self.count_neighbors(x as i32, y as i32);
+        self.cells = neighbor_counts
+            .into_iter()
+            .filter(|&(coord, count)| {
+                let table = if self.cells.contains(&coord) {
+                    &self.rule.survive
+                } else {
+                    &self.rule.birth
+                };
+                table[count as usize]
+            })
+            .map(|(coord, _)| coord)
+            .collect();
 
-                next_cells[x][y] = matches!((cell, neighbors), (true, 2) | (true, 3) | (false, 3));
-            }
+        self.generation += 1;
+        if self.seed_interval > 0 && self.generation.is_multiple_of(self.seed_interval) {
+            self.reseed();
         }
-        self.cells = next_cells;
     }
 
-    /// Counts the number of alive neighbors for the given cell.
-    ///
-    /// # Arguments
+    /// Randomly sets roughly `seed_density * ncols * nrows` cells alive, without clearing any
+    /// existing live cells, keeping otherwise-stabilized boards alive and interesting.
+    fn reseed(&mut self) {
+        let cells_to_seed = (self.ncols * self.nrows) as f32 * self.seed_density;
+        for _ in 0..cells_to_seed.round() as usize {
+            let x = (::rand::random::<usize>() % self.ncols.max(1)) as i64;
+            let y = (::rand::random::<usize>() % self.nrows.max(1)) as i64;
+            self.cells.insert((x, y));
+        }
+    }
+
+    /// Clears the board and stamps a pattern described in RLE (Run Length Encoded) format,
+    /// with its top-left corner placed at `origin`.
     ///
-    /// * `x` - The x coordinate of the cell.
-    /// * `y` - The y coordinate of the cell.
+    /// The header line has the form `x = <w>, y = <h>, rule = B3/S23` (the `rule` part, if
+    /// present, replaces `self.rule`); lines starting with `#` are treated as comments and
+    /// skipped. The body is a run-length-encoded sequence of tags: `b` for a dead cell, `o` for
+    /// a live cell, `$` to end the current row (a count before `$` skips that many rows), and
+    /// `!` to terminate the pattern.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// The number of alive neighbors.
-    fn count_neighbors(&self, x: i32, y: i32) -> usize {
-        let mut count = 0;
-        for dx in -1..=1 {
-            let nx = x + dx;
-            if nx < 0 || nx >= self.ncols as i32 {
-                // checks if neighbor's x is out of bounds
-                continue;
-            }
-            for dy in -1..=1 {
-                let ny = y + dy;
-                if ny < 0 || ny >= self.nrows as i32 {
-                    // checks if neighbor's y is out of bounds
-                    continue;
+    /// Returns an [`RleParseError`] if the header is missing or the body contains an
+    /// unrecognized character.
+    pub fn load_rle(&mut self, rle: &str, origin: (i64, i64)) -> Result<(), RleParseError> {
+        let mut lines = rle.lines().filter(|line| !line.trim_start().starts_with('#'));
+        let header = lines.next().ok_or_else(|| RleParseError::new("missing header line"))?;
+
+        let mut rule = self.rule;
+        if let Some(rule_part) = header
+            .split(',')
+            .find_map(|part| part.trim().strip_prefix("rule ="))
+        {
+            rule = Rule::parse(rule_part.trim())
+                .map_err(|err| RleParseError::new(format!("invalid rule in header: {err}")))?;
+        }
+
+        // Decoded into a separate set so that a parse error leaves `self` untouched.
+        let mut cells = HashSet::new();
+
+        let (origin_x, origin_y) = origin;
+        let (mut x, mut y) = (origin_x, origin_y);
+        let mut count: Option<i64> = None;
+        let body: String = lines.collect::<Vec<_>>().join("");
+
+        for ch in body.chars() {
+            match ch {
+                '0'..='9' => {
+                    let digit = ch.to_digit(10).expect("matched on '0'..='9'") as i64;
+                    count = Some(count.unwrap_or(0) * 10 + digit);
                 }
-                if nx == x && ny == y {
-                    continue;
+                'b' => x += count.take().unwrap_or(1),
+                'o' => {
+                    for _ in 0..count.take().unwrap_or(1) {
+                        cells.insert((x, y));
+                        x += 1;
+                    }
                 }
-                if self.cells[nx as usize][ny as usize] {
-                    count += 1;
+                '$' => {
+                    y += count.take().unwrap_or(1);
+                    x = origin_x;
                 }
+                '!' => break,
+                ch if ch.is_whitespace() => {}
+                ch => return Err(RleParseError::new(format!("unexpected character '{ch}'"))),
             }
         }
-        count
+
+        self.rule = rule;
+        self.cells = cells;
+        Ok(())
+    }
+
+    /// Encodes the live cells' bounding box as an RLE (Run Length Encoded) pattern string.
+    pub fn to_rle(&self) -> String {
+        let rulestring = self.rule.to_rulestring();
+
+        let Some((min_x, max_x, min_y, max_y)) = self.bounding_box() else {
+            return format!("x = 0, y = 0, rule = {rulestring}\n!\n");
+        };
+
+        let mut body = String::new();
+        for y in min_y..=max_y {
+            let row: Vec<bool> = (min_x..=max_x).map(|x| self.cells.contains(&(x, y))).collect();
+            let mut i = 0;
+            while i < row.len() {
+                let alive = row[i];
+                let start = i;
+                while i < row.len() && row[i] == alive {
+                    i += 1;
+                }
+                // A trailing dead run needs no tag: nothing follows it on the row anyway.
+                if alive || i < row.len() {
+                    let run_len = i - start;
+                    if run_len > 1 {
+                        body.push_str(&run_len.to_string());
+                    }
+                    body.push(if alive { 'o' } else { 'b' });
+                }
+            }
+            body.push('$');
+        }
+        body.pop();
+        body.push('!');
+
+        format!(
+            "x = {}, y = {}, rule = {rulestring}\n{body}\n",
+            max_x - min_x + 1,
+            max_y - min_y + 1
+        )
+    }
+
+    /// Returns `(min_x, max_x, min_y, max_y)` spanning all live cells, or `None` if the board is empty.
+    fn bounding_box(&self) -> Option<(i64, i64, i64, i64)> {
+        let mut cells = self.cells.iter();
+        let &(first_x, first_y) = cells.next()?;
+        let (mut min_x, mut max_x, mut min_y, mut max_y) = (first_x, first_x, first_y, first_y);
+        for &(x, y) in cells {
+            min_x = min_x.min(x);
+            max_x = max_x.max(x);
+            min_y = min_y.min(y);
+            max_y = max_y.max(y);
+        }
+        Some((min_x, max_x, min_y, max_y))
     }
 }
 
+/// Error returned when a string is not valid RLE (Run Length Encoded) pattern data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RleParseError {
+    reason: String,
+}
+
+impl RleParseError {
+    fn new(reason: impl Into<String>) -> Self {
+        Self { reason: reason.into() }
+    }
+}
+
+impl fmt::Display for RleParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid RLE pattern: {}", self.reason)
+    }
+}
+
+impl std::error::Error for RleParseError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_create_new_game() {
-        let game = Game::new(40, 30);
-        assert_eq!(game.cells.len(), 40);
-        assert_eq!(game.cells[0].len(), 30);
-    }
-
-    #[rustfmt::skip]
-    #[test]
-    fn test_count_neighbors() {
-        let mut game = Game::new(5, 5);
-        game.cells = vec![
-            vec![false, true, false, true, false],
-            vec![true, true, true, false, true],
-            vec![false, false, true, false, false],
-            vec![true, false, false, true, true],
-            vec![false, true, false, true, false],
-        ];
-        assert_eq!(game.count_neighbors(0, 0), 3);
-        assert_eq!(game.count_neighbors(0, 4), 2);
-        assert_eq!(game.count_neighbors(2, 2), 3);
-        assert_eq!(game.count_neighbors(4, 4), 3);
-    }
-
-    #[rustfmt::skip]
-    #[test]
-    fn test_update() {
-        let mut game = Game::new(5, 5);
-        game.cells = vec![
-            vec![false, true, false, true, false],
-            vec![true, true, true, false, true],
-            vec![false, false, true, false, false],
-            vec![true, false, false, true, true],
-            vec![false, true, false, true, false],
-        ];
+        let game = Game::new(40, 30, Rule::default());
+        assert!(game.cells.is_empty());
+    }
+
+    #[test]
+    fn test_toggle_cell_state() {
+        let mut game = Game::new(5, 5, Rule::default());
+        game.toggle_cell_state(2, 3);
+        assert!(game.cells.contains(&(2, 3)));
+        game.toggle_cell_state(2, 3);
+        assert!(!game.cells.contains(&(2, 3)));
+    }
+
+    #[test]
+    fn test_set_cell_state() {
+        let mut game = Game::new(5, 5, Rule::default());
+        game.set_cell_state(2, 3, true);
+        assert!(game.cells.contains(&(2, 3)));
+        game.set_cell_state(2, 3, true);
+        assert!(game.cells.contains(&(2, 3)));
+        game.set_cell_state(2, 3, false);
+        assert!(!game.cells.contains(&(2, 3)));
+    }
+
+    #[test]
+    fn test_toggle_cell_state_out_of_bounds() {
+        let mut game = Game::new(5, 5, Rule::default());
+        game.toggle_cell_state(10, 10);
+        assert!(game.cells.is_empty());
+    }
+
+    #[test]
+    fn test_update_blinker() {
+        // A vertical blinker at x = 2 should become a horizontal blinker after one generation.
+        let mut game = Game::new(5, 5, Rule::default());
+        game.cells = [(2, 1), (2, 2), (2, 3)].into_iter().collect();
+
         game.update();
-        let expected = vec![
-            vec![true, true, false, true, false],
-            vec![true, false, false, false, false],
-            vec![true, false, true, false, true],
-            vec![false, true, false, true, true],
-            vec![false, false, true, true, true],
-        ];
+
+        let expected: HashSet<(i64, i64)> = [(1, 2), (2, 2), (3, 2)].into_iter().collect();
         assert_eq!(game.cells, expected);
     }
+
+    #[test]
+    fn test_update_glider_survives_past_board_edge() {
+        // A glider placed so its next step would fall outside the original board dimensions
+        // must still be tracked, since the grid is no longer bounded by a dense array.
+        let mut game = Game::new(3, 3, Rule::default());
+        game.cells = [(4, 3), (5, 4), (3, 5), (4, 5), (5, 5)].into_iter().collect();
+
+        game.update();
+
+        assert!(!game.cells.is_empty());
+    }
+
+    #[test]
+    fn test_update_dead_cell_without_neighbors_stays_dead() {
+        let mut game = Game::new(5, 5, Rule::default());
+        game.cells = [(2, 2)].into_iter().collect();
+
+        game.update();
+
+        assert!(game.cells.is_empty());
+    }
+
+    #[test]
+    fn test_update_respects_custom_rule() {
+        // Seeds (B2/S): no cell ever survives, but a dead cell is born with exactly 2 neighbors.
+        let mut game = Game::new(5, 5, Rule::parse("B2/S").unwrap());
+        game.cells = [(2, 2), (2, 3)].into_iter().collect();
+
+        game.update();
+
+        let expected: HashSet<(i64, i64)> = [(1, 2), (1, 3), (3, 2), (3, 3)].into_iter().collect();
+        assert_eq!(game.cells, expected);
+    }
+
+    #[test]
+    fn test_update_dead_boundary_corners_die() {
+        // Without wrapping, the four corners of the board have no live neighbors and die.
+        let mut game = Game::new(3, 3, Rule::default());
+        game.cells = [(0, 0), (2, 0), (0, 2), (2, 2)].into_iter().collect();
+
+        game.update();
+
+        assert!(game.cells.is_empty());
+    }
+
+    #[test]
+    fn test_update_toroidal_boundary_wraps() {
+        // On a toroidal 3x3 board, the four corners are mutually adjacent, forming a stable block.
+        let mut game = Game::new(3, 3, Rule::default());
+        game.boundary = BoundaryMode::Toroidal;
+        let corners: HashSet<(i64, i64)> = [(0, 0), (2, 0), (0, 2), (2, 2)].into_iter().collect();
+        game.cells = corners.clone();
+
+        game.update();
+
+        assert_eq!(game.cells, corners);
+    }
+
+    #[test]
+    fn test_set_boundary_wraps_cells_that_escaped_in_dead_mode() {
+        // A cell that drifted outside the board while unbounded in `Dead` mode must not vanish
+        // the instant the boundary switches to `Toroidal`; it should wrap back into range.
+        let mut game = Game::new(3, 3, Rule::default());
+        game.cells = [(-1, 4)].into_iter().collect();
+
+        game.set_boundary(BoundaryMode::Toroidal);
+
+        assert_eq!(game.boundary, BoundaryMode::Toroidal);
+        assert_eq!(game.cells, [(2, 1)].into_iter().collect());
+    }
+
+    #[test]
+    fn test_update_toroidal_single_row_board_has_no_self_neighbors() {
+        // On a 1-row board, every dy offset wraps back into the same row, so a naive wrap could
+        // both miscount a cell as its own neighbor and triple-count its two real neighbors
+        // (one born from 3 duplicated offsets apiece). A lone live cell has no real neighbors at
+        // all, so the whole board must die out.
+        let mut game = Game::new(3, 1, Rule::default());
+        game.boundary = BoundaryMode::Toroidal;
+        game.cells = [(0, 0)].into_iter().collect();
+
+        game.update();
+
+        assert!(game.cells.is_empty());
+    }
+
+    #[test]
+    fn test_update_toroidal_single_column_board_has_no_self_neighbors() {
+        // Mirror of the single-row case along the other axis: every dx offset wraps back into
+        // the same column, so a lone live cell must not spuriously give birth to neighbors.
+        let mut game = Game::new(1, 3, Rule::default());
+        game.boundary = BoundaryMode::Toroidal;
+        game.cells = [(0, 0)].into_iter().collect();
+
+        game.update();
+
+        assert!(game.cells.is_empty());
+    }
+
+    #[test]
+    fn test_load_rle_glider() {
+        let mut game = Game::new(10, 10, Rule::default());
+        game.load_rle("x = 3, y = 3, rule = B3/S23\nbob$2bo$3o!\n", (0, 0)).unwrap();
+
+        let expected: HashSet<(i64, i64)> =
+            [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)].into_iter().collect();
+        assert_eq!(game.cells, expected);
+    }
+
+    #[test]
+    fn test_load_rle_applies_origin_and_clears_previous_cells() {
+        let mut game = Game::new(10, 10, Rule::default());
+        game.cells = [(5, 5)].into_iter().collect();
+
+        game.load_rle("x = 1, y = 1\no!\n", (3, 4)).unwrap();
+
+        assert_eq!(game.cells, [(3, 4)].into_iter().collect());
+    }
+
+    #[test]
+    fn test_load_rle_skips_comments_and_applies_rule() {
+        let mut game = Game::new(10, 10, Rule::default());
+        game.load_rle("#N Seeds test\nx = 1, y = 1, rule = B2/S\no!\n", (0, 0)).unwrap();
+
+        assert_eq!(game.rule, Rule::parse("B2/S").unwrap());
+    }
+
+    #[test]
+    fn test_load_rle_rejects_missing_header() {
+        let mut game = Game::new(10, 10, Rule::default());
+        assert!(game.load_rle("", (0, 0)).is_err());
+    }
+
+    #[test]
+    fn test_load_rle_rejects_unexpected_character() {
+        let mut game = Game::new(10, 10, Rule::default());
+        assert!(game.load_rle("x = 1, y = 1\nx!\n", (0, 0)).is_err());
+    }
+
+    #[test]
+    fn test_to_rle_empty_board() {
+        let game = Game::new(5, 5, Rule::default());
+        assert_eq!(game.to_rle(), "x = 0, y = 0, rule = B3/S23\n!\n");
+    }
+
+    #[test]
+    fn test_to_rle_round_trips_through_load_rle() {
+        let mut game = Game::new(10, 10, Rule::default());
+        game.cells = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)].into_iter().collect();
+
+        let rle = game.to_rle();
+
+        let mut reloaded = Game::new(10, 10, Rule::default());
+        reloaded.load_rle(&rle, (0, 0)).unwrap();
+        assert_eq!(reloaded.cells, game.cells);
+    }
+
+    #[test]
+    fn test_update_increments_generation() {
+        let mut game = Game::new(5, 5, Rule::default());
+        assert_eq!(game.generation, 0);
+
+        game.update();
+        assert_eq!(game.generation, 1);
+
+        game.update();
+        assert_eq!(game.generation, 2);
+    }
+
+    #[test]
+    fn test_seed_interval_zero_disables_reseeding() {
+        let mut game = Game::new(5, 5, Rule::default());
+        game.seed_density = 1.0;
+
+        game.update();
+
+        assert!(game.cells.is_empty());
+    }
+
+    #[test]
+    fn test_reseed_triggers_on_interval() {
+        let mut game = Game::new(5, 5, Rule::default());
+        game.seed_interval = 1;
+        game.seed_density = 1.0;
+
+        game.update();
+
+        assert!(!game.cells.is_empty());
+    }
 }
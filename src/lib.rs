@@ -13,3 +13,7 @@ pub mod game;
 /// It includes methods for drawing the grid, cells, and UI elements, as well as processing user input.
 /// This module represents both the view and controller parts of the application.
 pub mod board_renderer;
+
+/// The `rule` module defines the birth/survival rule used to advance the game, parsed from
+/// standard B/S rulestring notation.
+pub mod rule;
@@ -1,4 +1,4 @@
-use conways_game_of_life::{board_renderer::BoardRenderer, game::Game};
+use conways_game_of_life::{board_renderer::BoardRenderer, game::Game, rule::Rule};
 use macroquad::window::*;
 use std::num::NonZeroUsize;
 
@@ -51,7 +51,7 @@ async fn main() {
                 nrows: nrows.get(),
                 cell_size: cell_size.get(),
             };
-            let mut game = Game::new(ncols.get(), nrows.get());
+            let mut game = Game::new(ncols.get(), nrows.get(), Rule::default());
             board.run(&mut game).await;
         }
         _ => {
@@ -0,0 +1,157 @@
+use std::fmt;
+
+/// A birth/survival rule for the cellular automaton, parsed from standard B/S rulestring
+/// notation (e.g. `"B3/S23"` for Conway's Game of Life, `"B36/S23"` for HighLife).
+///
+/// `birth[n]` and `survive[n]` are indexed by live-neighbor count `0..=8` and say whether a
+/// dead or live cell respectively should be alive in the next generation when it has `n`
+/// live neighbors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rule {
+    pub(crate) birth: [bool; 9],
+    pub(crate) survive: [bool; 9],
+}
+
+impl Rule {
+    /// Parses a rulestring of the form `"B<digits>/S<digits>"`, where each digit is a
+    /// live-neighbor count in `0..=8`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rulestring` - The rulestring to parse, e.g. `"B3/S23"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RuleParseError`] if the string is missing its `B`/`S` parts or contains
+    /// a digit outside `0..=8`.
+    pub fn parse(rulestring: &str) -> Result<Self, RuleParseError> {
+        let (b_part, s_part) = rulestring
+            .split_once('/')
+            .ok_or_else(|| RuleParseError::new(rulestring, "missing '/' separator"))?;
+
+        let b_digits = b_part
+            .strip_prefix('B')
+            .ok_or_else(|| RuleParseError::new(rulestring, "birth part must start with 'B'"))?;
+        let s_digits = s_part
+            .strip_prefix('S')
+            .ok_or_else(|| RuleParseError::new(rulestring, "survival part must start with 'S'"))?;
+
+        Ok(Self {
+            birth: Self::parse_digits(b_digits, rulestring)?,
+            survive: Self::parse_digits(s_digits, rulestring)?,
+        })
+    }
+
+    fn parse_digits(digits: &str, rulestring: &str) -> Result<[bool; 9], RuleParseError> {
+        let mut table = [false; 9];
+        for ch in digits.chars() {
+            let n = ch
+                .to_digit(10)
+                .filter(|&n| n <= 8)
+                .ok_or_else(|| RuleParseError::new(rulestring, "digits must be in range 0..=8"))?;
+            table[n as usize] = true;
+        }
+        Ok(table)
+    }
+
+    /// Formats this rule back into `"B<digits>/S<digits>"` notation.
+    pub fn to_rulestring(&self) -> String {
+        let digits = |table: &[bool; 9]| -> String {
+            (0..=8).filter(|&n| table[n]).map(|n| n.to_string()).collect()
+        };
+        format!("B{}/S{}", digits(&self.birth), digits(&self.survive))
+    }
+
+    /// Conway's original rule: a dead cell is born with exactly 3 live neighbors, and a live
+    /// cell survives with 2 or 3 (`"B3/S23"`).
+    pub fn conway() -> Self {
+        Self::parse("B3/S23").expect("conway rulestring is valid")
+    }
+
+    /// HighLife: Conway's rule plus births on 6 neighbors, notable for its replicator pattern
+    /// (`"B36/S23"`).
+    pub fn highlife() -> Self {
+        Self::parse("B36/S23").expect("highlife rulestring is valid")
+    }
+
+    /// Seeds: every live cell dies every generation, but dead cells with exactly 2 neighbors
+    /// are born (`"B2/S"`).
+    pub fn seeds() -> Self {
+        Self::parse("B2/S").expect("seeds rulestring is valid")
+    }
+}
+
+impl Default for Rule {
+    /// Defaults to Conway's original rule.
+    fn default() -> Self {
+        Self::conway()
+    }
+}
+
+/// Error returned when a rulestring does not match the `B<digits>/S<digits>` format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleParseError {
+    rulestring: String,
+    reason: &'static str,
+}
+
+impl RuleParseError {
+    fn new(rulestring: &str, reason: &'static str) -> Self {
+        Self {
+            rulestring: rulestring.to_string(),
+            reason,
+        }
+    }
+}
+
+impl fmt::Display for RuleParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid rulestring \"{}\": {}", self.rulestring, self.reason)
+    }
+}
+
+impl std::error::Error for RuleParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_conway() {
+        let rule = Rule::parse("B3/S23").unwrap();
+        assert_eq!(rule, Rule::conway());
+        assert!(rule.birth[3]);
+        assert!(!rule.birth[2]);
+        assert!(rule.survive[2] && rule.survive[3]);
+        assert!(!rule.survive[4]);
+    }
+
+    #[test]
+    fn test_parse_seeds_has_no_survivors() {
+        let rule = Rule::parse("B2/S").unwrap();
+        assert!(rule.birth[2]);
+        assert!(rule.survive.iter().all(|&s| !s));
+    }
+
+    #[test]
+    fn test_parse_rejects_missing_separator() {
+        assert!(Rule::parse("B3S23").is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_missing_prefix() {
+        assert!(Rule::parse("3/S23").is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_out_of_range_digit() {
+        assert!(Rule::parse("B9/S23").is_err());
+    }
+
+    #[test]
+    fn test_to_rulestring_round_trips() {
+        assert_eq!(Rule::conway().to_rulestring(), "B3/S23");
+        assert_eq!(Rule::highlife().to_rulestring(), "B36/S23");
+        assert_eq!(Rule::seeds().to_rulestring(), "B2/S");
+    }
+}